@@ -0,0 +1,85 @@
+/// Which of Rust's four comment forms a [`crate::TokenKind::Comment`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentFlavor {
+    /// A plain `//` line comment.
+    LineComment,
+    /// A plain `/* */` block comment.
+    BlockComment,
+    /// An outer doc comment: `///` or `/** */`, attached to the item that follows it.
+    OuterDoc,
+    /// An inner doc comment: `//!` or `/*! */`, attached to the item it appears inside.
+    InnerDoc,
+}
+
+impl CommentFlavor {
+    pub fn is_doc(self) -> bool {
+        matches!(self, CommentFlavor::OuterDoc | CommentFlavor::InnerDoc)
+    }
+
+    /// Classifies a line comment from the text immediately after its
+    /// leading `//`. Four or more slashes (`////`) is a plain banner-style
+    /// comment, not a doc comment, matching rustc.
+    pub fn of_line(after_slashes: &str) -> CommentFlavor {
+        if after_slashes.starts_with('!') {
+            CommentFlavor::InnerDoc
+        } else if after_slashes.starts_with('/') && !after_slashes[1..].starts_with('/') {
+            CommentFlavor::OuterDoc
+        } else {
+            CommentFlavor::LineComment
+        }
+    }
+
+    /// Classifies a block comment from the text immediately after its
+    /// leading `/*`. An empty `/**/` and three-or-more-star `/***` are
+    /// plain comments, not doc comments, matching rustc.
+    pub fn of_block(after_open: &str) -> CommentFlavor {
+        if after_open.starts_with('!') {
+            CommentFlavor::InnerDoc
+        } else if after_open.starts_with('*')
+            && !after_open[1..].starts_with('*')
+            && !after_open[1..].starts_with('/')
+        {
+            CommentFlavor::OuterDoc
+        } else {
+            CommentFlavor::BlockComment
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_flavors() {
+        assert_eq!(CommentFlavor::of_line(" plain"), CommentFlavor::LineComment);
+        assert_eq!(CommentFlavor::of_line("/ outer doc"), CommentFlavor::OuterDoc);
+        assert_eq!(CommentFlavor::of_line("! inner doc"), CommentFlavor::InnerDoc);
+    }
+
+    #[test]
+    fn four_slashes_is_not_a_doc_comment() {
+        // `////` means the text right after the leading `//` starts with another `//`.
+        assert_eq!(CommentFlavor::of_line("// banner"), CommentFlavor::LineComment);
+    }
+
+    #[test]
+    fn block_flavors() {
+        assert_eq!(CommentFlavor::of_block(" plain */"), CommentFlavor::BlockComment);
+        assert_eq!(CommentFlavor::of_block("* outer doc */"), CommentFlavor::OuterDoc);
+        assert_eq!(CommentFlavor::of_block("! inner doc */"), CommentFlavor::InnerDoc);
+        // `/**/` (empty, i.e. text right after `/*` is just `*/`) and
+        // `/***` (three-plus stars, i.e. another `*` right after the one
+        // that would otherwise start a doc comment) are plain, not doc.
+        assert_eq!(CommentFlavor::of_block("*/"), CommentFlavor::BlockComment);
+        assert_eq!(CommentFlavor::of_block("** banner */"), CommentFlavor::BlockComment);
+    }
+
+    #[test]
+    fn is_doc_matches_flavor() {
+        assert!(CommentFlavor::OuterDoc.is_doc());
+        assert!(CommentFlavor::InnerDoc.is_doc());
+        assert!(!CommentFlavor::LineComment.is_doc());
+        assert!(!CommentFlavor::BlockComment.is_doc());
+    }
+}
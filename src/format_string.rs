@@ -0,0 +1,173 @@
+use crate::token::{Position, Span, Token, TokenKind};
+
+/// Names of macros whose first string-literal argument follows the
+/// `std::fmt` placeholder syntax, so their string literal should be
+/// descended into by [`scan_placeholders`].
+const FORMAT_MACROS: &[&str] = &[
+    "print",
+    "println",
+    "eprint",
+    "eprintln",
+    "format",
+    "format_args",
+    "write",
+    "writeln",
+    "panic",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "todo",
+    "unimplemented",
+    "unreachable",
+];
+
+/// Whether `name` is a format-family macro (without its trailing `!`).
+pub fn is_format_macro(name: &str) -> bool {
+    FORMAT_MACROS.contains(&name)
+}
+
+/// Scans `text` - the contents of a format string between its quotes -
+/// for `{}`-style placeholders, returning the literal-text runs
+/// interleaved with [`TokenKind::PlaceholderArg`] / [`TokenKind::PlaceholderSpec`]
+/// tokens.
+///
+/// `{{` and `}}` are literal escapes, not placeholders. Each `{...}` is
+/// split on its first `:` into an argument part (empty, a positional
+/// integer, or a named identifier) and an optional format spec (fill,
+/// align, sign, `#`, `0`, width, `.precision`, and a trailing type char).
+/// `base` is `text`'s first character's position in the original source,
+/// so every returned span is in the caller's coordinates, not relative
+/// to `text`.
+pub fn scan_placeholders(text: &str, base: Position) -> Vec<Token> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut pos = base;
+    let mut literal_start = pos;
+    let mut k = 0;
+
+    let flush_literal = |tokens: &mut Vec<Token>, literal_start: Position, pos: Position| {
+        if pos.byte > literal_start.byte {
+            tokens.push(Token {
+                kind: TokenKind::FormatLiteral,
+                span: Span { start: literal_start, end: pos },
+                text: text[literal_start.byte - base.byte..pos.byte - base.byte].to_string(),
+                sub_tokens: Vec::new(),
+            });
+        }
+    };
+
+    while k < chars.len() {
+        let (_, c) = chars[k];
+
+        if c == '{' && chars.get(k + 1).map(|&(_, c)| c) == Some('{') {
+            pos.advance(c);
+            pos.advance(chars[k + 1].1);
+            k += 2;
+            continue;
+        }
+        if c == '}' && chars.get(k + 1).map(|&(_, c)| c) == Some('}') {
+            pos.advance(c);
+            pos.advance(chars[k + 1].1);
+            k += 2;
+            continue;
+        }
+
+        if c == '{' {
+            flush_literal(&mut tokens, literal_start, pos);
+            pos.advance(c);
+            k += 1;
+
+            let arg_start = pos;
+            while k < chars.len() && !matches!(chars[k].1, '}' | ':') {
+                pos.advance(chars[k].1);
+                k += 1;
+            }
+            if pos.byte > arg_start.byte {
+                tokens.push(Token {
+                    kind: TokenKind::PlaceholderArg,
+                    span: Span { start: arg_start, end: pos },
+                    text: text[arg_start.byte - base.byte..pos.byte - base.byte].to_string(),
+                    sub_tokens: Vec::new(),
+                });
+            }
+
+            if chars.get(k).map(|&(_, c)| c) == Some(':') {
+                pos.advance(':');
+                k += 1;
+                let spec_start = pos;
+                while k < chars.len() && chars[k].1 != '}' {
+                    pos.advance(chars[k].1);
+                    k += 1;
+                }
+                if pos.byte > spec_start.byte {
+                    tokens.push(Token {
+                        kind: TokenKind::PlaceholderSpec,
+                        span: Span { start: spec_start, end: pos },
+                        text: text[spec_start.byte - base.byte..pos.byte - base.byte].to_string(),
+                        sub_tokens: Vec::new(),
+                    });
+                }
+            }
+
+            if chars.get(k).map(|&(_, c)| c) == Some('}') {
+                pos.advance('}');
+                k += 1;
+            }
+            literal_start = pos;
+            continue;
+        }
+
+        pos.advance(c);
+        k += 1;
+    }
+
+    flush_literal(&mut tokens, literal_start, pos);
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds_and_text(tokens: &[Token]) -> Vec<(&TokenKind, &str)> {
+        tokens.iter().map(|t| (&t.kind, t.text.as_str())).collect()
+    }
+
+    #[test]
+    fn escaped_braces_stay_literal() {
+        // `{{` and `}}` are not placeholders, so the whole run - including
+        // the doubled braces, kept verbatim like every other token's text -
+        // comes back as a single literal run.
+        let tokens = scan_placeholders("{{literal}}", Position::default());
+        assert_eq!(
+            kinds_and_text(&tokens),
+            vec![(&TokenKind::FormatLiteral, "{{literal}}")]
+        );
+    }
+
+    #[test]
+    fn positional_named_and_spec_placeholders() {
+        let tokens = scan_placeholders("{0:.2} {name} {:b} {:?}", Position::default());
+        assert_eq!(
+            kinds_and_text(&tokens),
+            vec![
+                (&TokenKind::PlaceholderArg, "0"),
+                (&TokenKind::PlaceholderSpec, ".2"),
+                (&TokenKind::FormatLiteral, " "),
+                (&TokenKind::PlaceholderArg, "name"),
+                (&TokenKind::FormatLiteral, " "),
+                (&TokenKind::PlaceholderSpec, "b"),
+                (&TokenKind::FormatLiteral, " "),
+                (&TokenKind::PlaceholderSpec, "?"),
+            ]
+        );
+    }
+
+    #[test]
+    fn is_format_macro_covers_the_whole_family() {
+        for name in ["print", "println", "write", "writeln", "assert_eq", "assert_ne"] {
+            assert!(is_format_macro(name), "{name} should be a recognized format macro");
+        }
+        assert!(!is_format_macro("vec"));
+    }
+}
@@ -0,0 +1,343 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::comment::CommentFlavor;
+use crate::format_string;
+use crate::number;
+use crate::token::{Position, Span, Token, TokenKind};
+
+/// Tracks whether the cursor is inside a format-family macro call, so the
+/// call's format-string argument can be scanned for placeholders wherever
+/// it falls in the argument list (`write!`/`writeln!` and the `assert*!`
+/// family take one or more leading non-string arguments before it).
+/// Advances one step at a time as the matching `name`, `!`, and `(`
+/// tokens are seen, then tracks paren depth across the whole call so a
+/// nested call's own parens don't end it early.
+#[derive(Debug, Clone, Copy)]
+enum MacroCallState {
+    None,
+    SawMacroName,
+    SawBang,
+    /// Inside the call's outer parens. `depth` counts the call's own `(`
+    /// against any `)` seen so far (1 right after the opening paren).
+    /// `format_string_seen` is set once a string literal has been found
+    /// directly in the call's argument list (`depth == 1`), since only
+    /// the first one is the format string.
+    InCall { depth: u32, format_string_seen: bool },
+}
+
+/// Scans source text into [`Token`]s.
+///
+/// `Lexer` advances a single [`Position`] cursor left-to-right over the
+/// source, one `char` at a time, so the byte/char/UTF-16 offsets it
+/// records never drift apart.
+pub struct Lexer<'src> {
+    source: &'src str,
+    chars: Peekable<CharIndices<'src>>,
+    pos: Position,
+    macro_call: MacroCallState,
+}
+
+impl<'src> Lexer<'src> {
+    pub fn new(source: &'src str) -> Self {
+        Lexer {
+            source,
+            chars: source.char_indices().peekable(),
+            pos: Position::default(),
+            macro_call: MacroCallState::None,
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let (_, c) = self.chars.next()?;
+        self.pos.advance(c);
+        Some(c)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    /// Looks one `char` past [`Lexer::peek`], without consuming either.
+    fn peek_second(&self) -> Option<char> {
+        let mut ahead = self.chars.clone();
+        ahead.next();
+        ahead.next().map(|(_, c)| c)
+    }
+
+    /// Advances past `count` more `char`s, for sub-lexers (like
+    /// [`number::scan`]) that classify a literal from a str slice rather
+    /// than one `char` at a time.
+    fn bump_chars(&mut self, count: usize) {
+        for _ in 0..count {
+            self.bump();
+        }
+    }
+
+    /// Scans the next token, or `None` at end of input.
+    pub fn next_token(&mut self) -> Option<Token> {
+        let start = self.pos;
+        let c = self.peek()?;
+        let format_aware_string = matches!(
+            self.macro_call,
+            MacroCallState::InCall { depth: 1, format_string_seen: false }
+        );
+
+        let (kind, sub_tokens) = if c.is_whitespace() {
+            self.bump();
+            while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+                self.bump();
+            }
+            (TokenKind::Whitespace, Vec::new())
+        } else if c == '/' && self.peek_second() == Some('/') {
+            (self.scan_line_comment(), Vec::new())
+        } else if c == '/' && self.peek_second() == Some('*') {
+            (self.scan_block_comment(), Vec::new())
+        } else if c == '"' {
+            self.scan_string_literal(format_aware_string)
+        } else if c == 'b' && self.peek_second() == Some('\'') {
+            (self.scan_byte_literal(), Vec::new())
+        } else if c.is_ascii_digit() {
+            (self.scan_number(), Vec::new())
+        } else if c.is_alphabetic() || c == '_' {
+            self.bump();
+            while matches!(self.peek(), Some(c) if c.is_alphanumeric() || c == '_') {
+                self.bump();
+            }
+            (TokenKind::Ident, Vec::new())
+        } else {
+            self.bump();
+            (TokenKind::Unknown, Vec::new())
+        };
+
+        let span = Span { start, end: self.pos };
+        let text = self.source[start.byte..self.pos.byte].to_string();
+        self.update_macro_call_state(&kind, &text);
+
+        Some(Token { kind, span, text, sub_tokens })
+    }
+
+    /// Advances the format-macro-call tracking state machine on the token
+    /// just scanned. Whitespace never disturbs it. Once [`MacroCallState::InCall`],
+    /// the machine tracks paren depth across the whole argument list
+    /// (rather than just the token right after `(`) and only leaves that
+    /// state when the call's own closing paren is reached, so arguments
+    /// preceding the format string - the writer in `write!`/`writeln!`,
+    /// the compared values in `assert_eq!`/`assert_ne!` - don't reset it.
+    fn update_macro_call_state(&mut self, kind: &TokenKind, text: &str) {
+        if *kind == TokenKind::Whitespace {
+            return;
+        }
+
+        if let MacroCallState::InCall { depth, format_string_seen } = &mut self.macro_call {
+            match text {
+                "(" if *kind == TokenKind::Unknown => *depth += 1,
+                ")" if *kind == TokenKind::Unknown => *depth -= 1,
+                _ => {}
+            }
+            if *depth == 0 {
+                self.macro_call = MacroCallState::None;
+            } else if !*format_string_seen && *depth == 1 && *kind == TokenKind::StringLiteral {
+                *format_string_seen = true;
+            }
+            return;
+        }
+
+        self.macro_call = match (self.macro_call, kind, text) {
+            (_, TokenKind::Ident, name) if format_string::is_format_macro(name) => {
+                MacroCallState::SawMacroName
+            }
+            (MacroCallState::SawMacroName, TokenKind::Unknown, "!") => MacroCallState::SawBang,
+            (MacroCallState::SawBang, TokenKind::Unknown, "(") => {
+                MacroCallState::InCall { depth: 1, format_string_seen: false }
+            }
+            _ => MacroCallState::None,
+        };
+    }
+
+    /// Classifies the numeric literal starting at the cursor using
+    /// [`number::scan`], then advances past exactly the `char`s it claims.
+    fn scan_number(&mut self) -> TokenKind {
+        let literal = number::scan(&self.source[self.pos.byte..]);
+        let char_len = self.source[self.pos.byte..self.pos.byte + literal.len]
+            .chars()
+            .count();
+        self.bump_chars(char_len);
+        TokenKind::NumberLiteral(literal)
+    }
+
+    /// Scans a `//` line comment starting at the cursor, classifying it
+    /// by the text right after the leading slashes.
+    fn scan_line_comment(&mut self) -> TokenKind {
+        self.bump(); // '/'
+        self.bump(); // '/'
+        let flavor = CommentFlavor::of_line(&self.source[self.pos.byte..]);
+        while matches!(self.peek(), Some(c) if c != '\n') {
+            self.bump();
+        }
+        TokenKind::Comment(flavor)
+    }
+
+    /// Scans a `/* */` block comment starting at the cursor, classifying
+    /// it by the text right after the opening delimiter and tracking
+    /// nesting depth so the span ends at the matching outer `*/`.
+    fn scan_block_comment(&mut self) -> TokenKind {
+        self.bump(); // '/'
+        self.bump(); // '*'
+        let flavor = CommentFlavor::of_block(&self.source[self.pos.byte..]);
+
+        let mut depth = 1u32;
+        while depth > 0 {
+            match (self.peek(), self.peek_second()) {
+                (None, _) => break,
+                (Some('/'), Some('*')) => {
+                    self.bump();
+                    self.bump();
+                    depth += 1;
+                }
+                (Some('*'), Some('/')) => {
+                    self.bump();
+                    self.bump();
+                    depth -= 1;
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+        TokenKind::Comment(flavor)
+    }
+
+    /// Scans a string literal starting at the cursor. When
+    /// `format_aware` is set - meaning this string is the first string
+    /// literal directly in a format-family macro call's argument list,
+    /// whichever argument position it holds - its placeholders are broken out
+    /// into the returned sub-tokens via [`format_string::scan_placeholders`].
+    fn scan_string_literal(&mut self, format_aware: bool) -> (TokenKind, Vec<Token>) {
+        self.bump(); // opening quote
+        let body_start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == '"' {
+                break;
+            }
+            self.bump();
+            if c == '\\' {
+                self.bump();
+            }
+        }
+        let body_end_byte = self.pos.byte;
+        if self.peek() == Some('"') {
+            self.bump();
+        }
+
+        let sub_tokens = if format_aware {
+            format_string::scan_placeholders(&self.source[body_start.byte..body_end_byte], body_start)
+        } else {
+            Vec::new()
+        };
+        (TokenKind::StringLiteral, sub_tokens)
+    }
+
+    /// Scans a byte literal (`b'A'`, `b'\n'`, ...) starting at the cursor.
+    fn scan_byte_literal(&mut self) -> TokenKind {
+        self.bump(); // 'b'
+        self.bump(); // opening quote
+        if self.peek() == Some('\\') {
+            self.bump();
+        }
+        self.bump(); // the escaped or literal byte char
+        if self.peek() == Some('\'') {
+            self.bump();
+        }
+        TokenKind::ByteLiteral
+    }
+}
+
+impl<'src> Iterator for Lexer<'src> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        self.next_token()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_stay_in_lockstep_across_multibyte_and_astral_scalars() {
+        // "你" and "好" are each 3 UTF-8 bytes / 1 UTF-16 unit; 😻 is a 4-byte
+        // astral scalar that takes 2 UTF-16 units (a surrogate pair).
+        let tokens: Vec<Token> = Lexer::new("你好😻b").collect();
+
+        assert_eq!(tokens[0].text, "你好");
+        assert_eq!(tokens[0].span.start, Position::default());
+        assert_eq!(
+            tokens[0].span.end,
+            Position { byte: 6, char: 2, utf16: 2 }
+        );
+
+        assert_eq!(tokens[1].text, "😻");
+        assert_eq!(tokens[1].span.start, Position { byte: 6, char: 2, utf16: 2 });
+        assert_eq!(tokens[1].span.end, Position { byte: 10, char: 3, utf16: 4 });
+
+        assert_eq!(tokens[2].text, "b");
+        assert_eq!(tokens[2].span.start, Position { byte: 10, char: 3, utf16: 4 });
+        assert_eq!(tokens[2].span.end, Position { byte: 11, char: 4, utf16: 5 });
+    }
+
+    #[test]
+    fn write_and_assert_eq_format_strings_are_descended_despite_leading_args() {
+        let tokens: Vec<Token> = Lexer::new(r#"write!(f, "x={}", x)"#).collect();
+        let format_string = tokens
+            .iter()
+            .find(|t| t.kind == TokenKind::StringLiteral)
+            .unwrap();
+        assert!(
+            !format_string.sub_tokens.is_empty(),
+            "write!'s format string (2nd argument) should be scanned for placeholders"
+        );
+
+        let tokens: Vec<Token> = Lexer::new(r#"assert_eq!(a, b, "fail {}", x)"#).collect();
+        let format_string = tokens
+            .iter()
+            .find(|t| t.kind == TokenKind::StringLiteral)
+            .unwrap();
+        assert!(
+            !format_string.sub_tokens.is_empty(),
+            "assert_eq!'s format string (3rd argument) should be scanned for placeholders"
+        );
+    }
+
+    #[test]
+    fn nested_call_string_is_not_treated_as_the_format_string() {
+        let tokens: Vec<Token> = Lexer::new(r#"write!(f, "a={}", g("nested {}"))"#).collect();
+        let strings: Vec<&Token> = tokens
+            .iter()
+            .filter(|t| t.kind == TokenKind::StringLiteral)
+            .collect();
+
+        assert_eq!(strings[0].text, "\"a={}\"");
+        assert!(!strings[0].sub_tokens.is_empty());
+
+        assert_eq!(strings[1].text, "\"nested {}\"");
+        assert!(
+            strings[1].sub_tokens.is_empty(),
+            "a string nested inside another call's parens is not the macro's format string"
+        );
+    }
+
+    #[test]
+    fn nested_block_comments_end_at_the_matching_outer_close() {
+        let src = "/* a /* nested */ still open */x";
+        let tokens: Vec<Token> = Lexer::new(src).collect();
+
+        let comment = &tokens[0];
+        assert_eq!(comment.text, "/* a /* nested */ still open */");
+        assert_eq!(comment.kind, TokenKind::Comment(CommentFlavor::BlockComment));
+
+        let trailing = &tokens[1];
+        assert_eq!(trailing.text, "x");
+    }
+}
@@ -0,0 +1,18 @@
+//! A tokenizer for Rust source text.
+//!
+//! Every token carries its position in three coordinated coordinate
+//! systems at once - byte offset, Unicode scalar (`char`) index, and
+//! UTF-16 code unit index - computed in a single left-to-right pass over
+//! the source. This lets byte-oriented tools and UTF-16-speaking editors
+//! (LSP) consume the same token stream without re-deriving offsets.
+
+mod comment;
+mod format_string;
+mod lexer;
+mod number;
+mod token;
+
+pub use comment::CommentFlavor;
+pub use lexer::Lexer;
+pub use number::{NumberLiteral, Radix};
+pub use token::{Position, Span, Token, TokenKind};
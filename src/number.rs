@@ -0,0 +1,184 @@
+/// The radix a numeric literal was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Radix {
+    Binary,
+    Octal,
+    Decimal,
+    Hexadecimal,
+}
+
+impl Radix {
+    /// Whether `c` is a legal digit for this radix (ignoring the `_`
+    /// separator, which every radix accepts).
+    fn allows_digit(self, c: char) -> bool {
+        match self {
+            Radix::Binary => matches!(c, '0' | '1'),
+            Radix::Octal => matches!(c, '0'..='7'),
+            Radix::Decimal => c.is_ascii_digit(),
+            Radix::Hexadecimal => c.is_ascii_hexdigit(),
+        }
+    }
+}
+
+/// The known Rust integer and float suffixes. No suffix is a prefix of
+/// another, so the first one that matches is the whole suffix.
+const SUFFIXES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "f64",
+];
+
+/// A classified numeric literal, as produced by [`scan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberLiteral {
+    pub radix: Radix,
+    pub is_float: bool,
+    /// The type suffix, if any (e.g. `"i32"`, `"f32"`), with no leading text.
+    pub suffix: Option<String>,
+    /// Byte length of the literal within the text passed to [`scan`].
+    pub len: usize,
+}
+
+/// Classifies the numeric literal starting at the beginning of `text`.
+///
+/// `text` must start with an ASCII digit. Consumes the radix prefix
+/// (`0x`/`0o`/`0b`), digits and interior `_` separators, an optional
+/// fractional/exponent part (decimal only), and an optional type suffix.
+/// A trailing `_` is not consumed, and `0..3` scans as the literal `0`
+/// only - the `..` is left for the caller, since a `.` followed by another
+/// `.` never starts a fractional part.
+pub fn scan(text: &str) -> NumberLiteral {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    let radix = if chars[0] == '0' && chars.len() > 1 {
+        match chars[1] {
+            'x' => Some(Radix::Hexadecimal),
+            'o' => Some(Radix::Octal),
+            'b' => Some(Radix::Binary),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    let radix = match radix {
+        Some(r) => {
+            i += 2;
+            r
+        }
+        None => Radix::Decimal,
+    };
+
+    consume_digits(&chars, &mut i, radix);
+
+    let mut is_float = false;
+    if radix == Radix::Decimal {
+        if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(|c| *c != '.' && !c.is_alphabetic())
+        {
+            is_float = true;
+            i += 1;
+            consume_digits(&chars, &mut i, Radix::Decimal);
+        }
+        if matches!(chars.get(i), Some('e') | Some('E')) {
+            let mut j = i + 1;
+            if matches!(chars.get(j), Some('+') | Some('-')) {
+                j += 1;
+            }
+            if chars.get(j).is_some_and(|c| c.is_ascii_digit()) {
+                is_float = true;
+                i = j;
+                consume_digits(&chars, &mut i, Radix::Decimal);
+            }
+        }
+    }
+
+    let suffix = SUFFIXES
+        .iter()
+        .find(|s| chars[i..].starts_with(&s.chars().collect::<Vec<_>>()[..]))
+        .map(|s| {
+            i += s.len();
+            if s.starts_with('f') {
+                is_float = true;
+            }
+            s.to_string()
+        });
+
+    NumberLiteral {
+        radix,
+        is_float,
+        suffix,
+        len: chars[..i].iter().map(|c| c.len_utf8()).sum(),
+    }
+}
+
+fn consume_digits(chars: &[char], i: &mut usize, radix: Radix) {
+    while let Some(&c) = chars.get(*i) {
+        if radix.allows_digit(c) || c == '_' {
+            *i += 1;
+        } else {
+            break;
+        }
+    }
+    // A separator only belongs to the literal when it sits between two
+    // digits; one left dangling at the end is not part of the number.
+    while *i > 0 && chars[*i - 1] == '_' {
+        *i -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_start_is_not_swallowed_by_the_dot() {
+        let n = scan("0..3");
+        assert_eq!(n.radix, Radix::Decimal);
+        assert!(!n.is_float);
+        assert_eq!(n.len, 1);
+    }
+
+    #[test]
+    fn trailing_separator_is_not_consumed() {
+        let n = scan("100_");
+        assert_eq!(n.len, 3);
+    }
+
+    #[test]
+    fn interior_separators_are_consumed() {
+        let n = scan("100_0000_000");
+        assert_eq!(n.len, "100_0000_000".len());
+    }
+
+    #[test]
+    fn radix_prefixes() {
+        assert_eq!(scan("0xff").radix, Radix::Hexadecimal);
+        assert_eq!(scan("0o77").radix, Radix::Octal);
+        assert_eq!(scan("0b1111_0000").radix, Radix::Binary);
+    }
+
+    #[test]
+    fn float_fraction_and_exponent() {
+        let n = scan("10000.50");
+        assert!(n.is_float);
+        assert_eq!(n.len, "10000.50".len());
+
+        let n = scan("1e10");
+        assert!(n.is_float);
+        assert_eq!(n.len, "1e10".len());
+    }
+
+    #[test]
+    fn suffixes() {
+        let n = scan("31i32");
+        assert!(!n.is_float);
+        assert_eq!(n.suffix.as_deref(), Some("i32"));
+
+        let n = scan("2.0f32");
+        assert!(n.is_float);
+        assert_eq!(n.suffix.as_deref(), Some("f32"));
+
+        let n = scan("30u32");
+        assert_eq!(n.suffix.as_deref(), Some("u32"));
+    }
+}
@@ -0,0 +1,83 @@
+/// A single point in the source text, expressed in three coordinate
+/// systems at once.
+///
+/// The three counters always advance together over the same scan: a
+/// scalar that is `n` bytes in UTF-8 and `m` UTF-16 code units advances
+/// `byte` by `n`, `char` by 1, and `utf16` by `m`. Keeping them in
+/// lockstep means a token boundary recorded here is never ambiguous
+/// between the byte-oriented and UTF-16-oriented views of the same text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    /// Byte offset into the original UTF-8 source.
+    pub byte: usize,
+    /// Offset in Unicode scalar values (`char`s).
+    pub char: usize,
+    /// Offset in UTF-16 code units, as used by LSP-speaking editors.
+    pub utf16: usize,
+}
+
+impl Position {
+    /// Advances this position past `c`, keeping `byte`, `char`, and
+    /// `utf16` in lockstep so a token can never split a scalar.
+    pub(crate) fn advance(&mut self, c: char) {
+        self.byte += c.len_utf8();
+        self.char += 1;
+        self.utf16 += c.len_utf16();
+    }
+}
+
+/// A start/end pair of [`Position`]s delimiting a token in the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// The kind of a scanned [`Token`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    /// A run of whitespace.
+    Whitespace,
+    /// An identifier or keyword.
+    Ident,
+    /// A numeric literal, classified by [`crate::number::scan`].
+    NumberLiteral(crate::number::NumberLiteral),
+    /// A byte literal such as `b'A'`.
+    ByteLiteral,
+    /// A string literal, including its surrounding quotes.
+    ///
+    /// When it is the first string literal directly in a format-family
+    /// macro call's argument list, its
+    /// `{}`-style placeholders are additionally broken out into the
+    /// token's [`Token::sub_tokens`].
+    StringLiteral,
+    /// A run of literal text between (or around) placeholders in a
+    /// format string, as produced by [`crate::format_string::scan_placeholders`].
+    FormatLiteral,
+    /// The argument part of a `{}` placeholder - a positional index or a
+    /// name - as produced by [`crate::format_string::scan_placeholders`].
+    /// A placeholder with an empty argument part (`{}`, `{:?}`) gets no
+    /// `PlaceholderArg` token.
+    PlaceholderArg,
+    /// The format spec part of a `{:...}` placeholder, after the `:`, as
+    /// produced by [`crate::format_string::scan_placeholders`].
+    PlaceholderSpec,
+    /// A line or block comment, including doc comments. See
+    /// [`crate::comment::CommentFlavor`] for which of the four forms it is.
+    Comment(crate::comment::CommentFlavor),
+    /// A single character not yet classified by a more specific scanner.
+    Unknown,
+}
+
+/// A scanned token together with its source span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+    /// The token's exact source text.
+    pub text: String,
+    /// Sub-tokens nested within this token's span, such as the
+    /// placeholder tokens a format-aware [`TokenKind::StringLiteral`]
+    /// descends into. Empty for every other kind.
+    pub sub_tokens: Vec<Token>,
+}